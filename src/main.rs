@@ -1,14 +1,15 @@
 use itertools::Itertools as _;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-struct Data(&'static str);
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Data(String);
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct TxId(&'static str);
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct TxId(String);
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 enum OpKind {
     Read(Data),
     Write(Data),
@@ -16,7 +17,7 @@ enum OpKind {
     Abort,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 struct Op {
     kind: OpKind,
     tx: TxId,
@@ -24,7 +25,7 @@ struct Op {
 
 impl Display for Op {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self.kind {
+        match &self.kind {
             OpKind::Read(Data(dat)) => write!(f, "R_{}({})", self.tx.0, dat),
             OpKind::Write(Data(dat)) => write!(f, "W_{}({})", self.tx.0, dat),
             OpKind::Commit => write!(f, "C_{}", self.tx.0),
@@ -33,32 +34,82 @@ impl Display for Op {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParseOpError(String);
+
+impl Display for ParseOpError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "invalid operation token '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseOpError {}
+
+impl FromStr for Op {
+    type Err = ParseOpError;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        let err = || ParseOpError(token.to_string());
+
+        if let Some(rest) = token.strip_prefix("R_") {
+            let (tx, dat) = parse_access(rest).ok_or_else(err)?;
+            return Ok(Op { kind: OpKind::Read(Data(dat)), tx: TxId(tx) });
+        }
+
+        if let Some(rest) = token.strip_prefix("W_") {
+            let (tx, dat) = parse_access(rest).ok_or_else(err)?;
+            return Ok(Op { kind: OpKind::Write(Data(dat)), tx: TxId(tx) });
+        }
+
+        if let Some(tx) = token.strip_prefix("C_").filter(|tx| !tx.is_empty()) {
+            return Ok(Op { kind: OpKind::Commit, tx: TxId(tx.to_string()) });
+        }
+
+        if let Some(tx) = token.strip_prefix("A_").filter(|tx| !tx.is_empty()) {
+            return Ok(Op { kind: OpKind::Abort, tx: TxId(tx.to_string()) });
+        }
+
+        Err(err())
+    }
+}
+
+fn parse_access(rest: &str) -> Option<(String, String)> {
+    let (tx, paren) = rest.split_once('(')?;
+    let dat = paren.strip_suffix(')')?;
+
+    if tx.is_empty() || dat.is_empty() {
+        return None;
+    }
+
+    Some((tx.to_string(), dat.to_string()))
+}
+
 macro_rules! op {
     (r, $dat:expr, $tx_id:expr) => {
         Op {
-            kind: OpKind::Read(Data(stringify!($dat))),
-            tx: TxId(stringify!($tx_id)),
+            kind: OpKind::Read(Data(stringify!($dat).to_string())),
+            tx: TxId(stringify!($tx_id).to_string()),
         }
     };
 
     (w, $dat:expr, $tx_id:expr) => {
         Op {
-            kind: OpKind::Write(Data(stringify!($dat))),
-            tx: TxId(stringify!($tx_id)),
+            kind: OpKind::Write(Data(stringify!($dat).to_string())),
+            tx: TxId(stringify!($tx_id).to_string()),
         }
     };
 
     (c, $tx_id:expr) => {
         Op {
             kind: OpKind::Commit,
-            tx: TxId(stringify!($tx_id)),
+            tx: TxId(stringify!($tx_id).to_string()),
         }
     };
 
     (a, $tx_id:expr) => {
         Op {
             kind: OpKind::Abort,
-            tx: TxId(stringify!($tx_id)),
+            tx: TxId(stringify!($tx_id).to_string()),
         }
     };
 }
@@ -77,7 +128,7 @@ macro_rules! sched {
     }};
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 struct OpPair((Op, Op));
 
 #[derive(Debug)]
@@ -115,12 +166,12 @@ impl OpPair {
     }
 
     fn on_same_data(&self) -> bool {
-        let d1 = match self.0 .0.kind {
+        let d1 = match &self.0 .0.kind {
             OpKind::Read(d) | OpKind::Write(d) => d,
             _ => return false,
         };
 
-        let d2 = match self.0 .1.kind {
+        let d2 = match &self.0 .1.kind {
             OpKind::Read(d) | OpKind::Write(d) => d,
             _ => return false,
         };
@@ -130,10 +181,65 @@ impl OpPair {
 
     fn one_is_a_write(&self) -> bool {
         matches!(
-            (self.0 .0.kind, self.0 .1.kind),
+            (&self.0 .0.kind, &self.0 .1.kind),
             (OpKind::Write(..), _) | (_, OpKind::Write(..))
         )
     }
+
+    fn data(&self) -> Option<Data> {
+        match &self.0 .0.kind {
+            OpKind::Read(d) | OpKind::Write(d) => Some(d.clone()),
+            _ => None,
+        }
+    }
+
+    fn hazard(&self) -> Option<Hazard> {
+        match (&self.0 .0.kind, &self.0 .1.kind) {
+            (OpKind::Write(..), OpKind::Read(..)) => Some(Hazard::Raw),
+            (OpKind::Read(..), OpKind::Write(..)) => Some(Hazard::War),
+            (OpKind::Write(..), OpKind::Write(..)) => Some(Hazard::Waw),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Hazard {
+    /// Write-read: a read observes a prior write.
+    Raw,
+    /// Read-write: a write follows a prior read.
+    War,
+    /// Write-write: a write follows a prior write.
+    Waw,
+}
+
+impl Display for Hazard {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let s = match self {
+            Hazard::Raw => "RAW",
+            Hazard::War => "WAR",
+            Hazard::Waw => "WAW",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WriteDependency {
+    pair: OpPair,
+    hazard: Hazard,
+    data: Data,
+}
+
+impl Display for WriteDependency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}, {}): {} on {}",
+            self.pair.0 .0, self.pair.0 .1, self.hazard, self.data.0
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -162,8 +268,9 @@ impl Schedule {
     fn conflicting_pairs(&self) -> Vec<OpPair> {
         self.ops
             .iter()
+            .cloned()
             .combinations(2)
-            .map(|p| OpPair((*p[0], *p[1])))
+            .map(|p| OpPair((p[0].clone(), p[1].clone())))
             .filter(|p| p.is_conflicting())
             .collect()
     }
@@ -173,7 +280,7 @@ impl Schedule {
 
         for op in &self.ops {
             result
-                .entry(op.tx)
+                .entry(op.tx.clone())
                 .and_modify(|e| e.push(op.clone()))
                 .or_insert(vec![op.clone()]);
         }
@@ -183,6 +290,501 @@ impl Schedule {
             .map(|(id, ops)| Transaction { id, ops })
             .collect()
     }
+
+    /// Builds the precedence (serialization) graph: an edge `Ti -> Tj` means
+    /// some conflicting operation of `Ti` precedes one of `Tj` in the schedule.
+    fn precedence_graph(&self) -> PrecedenceGraph {
+        let mut edges: BTreeMap<TxId, BTreeSet<TxId>> = self
+            .transactions()
+            .into_iter()
+            .map(|tx| (tx.id, BTreeSet::new()))
+            .collect();
+
+        for OpPair((first, second)) in self.conflicting_pairs() {
+            edges.entry(first.tx).or_default().insert(second.tx);
+        }
+
+        PrecedenceGraph { edges }
+    }
+
+    fn is_conflict_serializable(&self) -> bool {
+        !self.precedence_graph().has_cycle()
+    }
+
+    fn serial_equivalent(&self) -> Option<Vec<TxId>> {
+        self.precedence_graph().topological_order()
+    }
+
+    fn reads_from(&self) -> Vec<(Op, Option<TxId>)> {
+        let mut last_write: BTreeMap<Data, TxId> = BTreeMap::new();
+        let mut result = Vec::new();
+
+        for op in &self.ops {
+            match &op.kind {
+                OpKind::Read(data) => {
+                    result.push((op.clone(), last_write.get(data).cloned()));
+                }
+                OpKind::Write(data) => {
+                    last_write.insert(data.clone(), op.tx.clone());
+                }
+                OpKind::Commit => {}
+                OpKind::Abort => {
+                    last_write.retain(|_, writer| *writer != op.tx);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn write_dependencies(&self) -> Vec<WriteDependency> {
+        self.conflicting_pairs()
+            .into_iter()
+            .filter_map(|pair| {
+                let hazard = pair.hazard()?;
+                let data = pair.data()?;
+                Some(WriteDependency { pair, hazard, data })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+#[derive(Debug)]
+struct PrecedenceGraph {
+    edges: BTreeMap<TxId, BTreeSet<TxId>>,
+}
+
+impl PrecedenceGraph {
+    fn has_cycle(&self) -> bool {
+        let mut colors: BTreeMap<TxId, Color> = self
+            .edges
+            .keys()
+            .map(|tx| (tx.clone(), Color::White))
+            .collect();
+
+        fn visit(
+            node: &TxId,
+            edges: &BTreeMap<TxId, BTreeSet<TxId>>,
+            colors: &mut BTreeMap<TxId, Color>,
+        ) -> bool {
+            colors.insert(node.clone(), Color::Gray);
+
+            for next in edges.get(node).into_iter().flatten() {
+                let color = colors[next];
+                match color {
+                    Color::Gray => return true,
+                    Color::White if visit(next, edges, colors) => return true,
+                    Color::White | Color::Black => {}
+                }
+            }
+
+            colors.insert(node.clone(), Color::Black);
+            false
+        }
+
+        let nodes: Vec<TxId> = self.edges.keys().cloned().collect();
+
+        nodes
+            .iter()
+            .any(|node| colors[node] == Color::White && visit(node, &self.edges, &mut colors))
+    }
+
+    /// Kahn's algorithm over in-degrees; `None` if the graph has a cycle.
+    fn topological_order(&self) -> Option<Vec<TxId>> {
+        let mut in_degree: BTreeMap<TxId, usize> =
+            self.edges.keys().map(|tx| (tx.clone(), 0)).collect();
+
+        for targets in self.edges.values() {
+            for tx in targets {
+                *in_degree.get_mut(tx).unwrap() += 1;
+            }
+        }
+
+        let mut ready: BTreeSet<TxId> = in_degree
+            .iter()
+            .filter(|&(_, &degree)| degree == 0)
+            .map(|(tx, _)| tx.clone())
+            .collect();
+
+        let mut order = Vec::new();
+
+        while let Some(node) = ready.iter().next().cloned() {
+            ready.remove(&node);
+            order.push(node.clone());
+
+            for next in self.edges.get(&node).into_iter().flatten() {
+                let degree = in_degree.get_mut(next).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.insert(next.clone());
+                }
+            }
+        }
+
+        (order.len() == self.edges.len()).then_some(order)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Recoverability {
+    Strict,
+    Cascadeless,
+    Recoverable,
+    NonRecoverable,
+}
+
+impl Display for Recoverability {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let s = match self {
+            Recoverability::Strict => "Strict",
+            Recoverability::Cascadeless => "Cascadeless",
+            Recoverability::Recoverable => "Recoverable",
+            Recoverability::NonRecoverable => "Not Recoverable",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum RecoverabilityViolation {
+    DirtyRead { reader: Op, writer: Op },
+    DirtyWrite { writer: Op, prior_writer: Op },
+    /// The reader committed before the transaction it dirty-read from did,
+    /// so the schedule can't be undone in commit order.
+    OutOfOrderCommit { reader_commit: Op, dirty_write: Op },
+}
+
+impl Display for RecoverabilityViolation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RecoverabilityViolation::DirtyRead { reader, writer } => {
+                write!(f, "{} reads data written by uncommitted {}", reader, writer)
+            }
+            RecoverabilityViolation::DirtyWrite { writer, prior_writer } => {
+                write!(
+                    f,
+                    "{} overwrites data written by uncommitted {}",
+                    writer, prior_writer
+                )
+            }
+            RecoverabilityViolation::OutOfOrderCommit {
+                reader_commit,
+                dirty_write,
+            } => {
+                write!(
+                    f,
+                    "{} reads {} then commits before its writer does",
+                    reader_commit, dirty_write
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RecoverabilityReport {
+    level: Recoverability,
+    violations: Vec<RecoverabilityViolation>,
+}
+
+impl Schedule {
+    fn recoverability(&self) -> RecoverabilityReport {
+        let mut last_write: BTreeMap<Data, (TxId, Op)> = BTreeMap::new();
+        let mut committed: BTreeSet<TxId> = BTreeSet::new();
+        let mut aborted: BTreeSet<TxId> = BTreeSet::new();
+        // For each reader transaction, the (writer transaction, dirty write op)
+        // pairs it has dirty-read from and not yet been checked against at commit.
+        let mut pending_reads_from: BTreeMap<TxId, Vec<(TxId, Op)>> = BTreeMap::new();
+
+        let mut dirty_reads = Vec::new();
+        let mut dirty_writes = Vec::new();
+        let mut out_of_order_commits = Vec::new();
+
+        for op in &self.ops {
+            match &op.kind {
+                OpKind::Read(data) => {
+                    if let Some((writer_tx, writer_op)) = last_write.get(data) {
+                        if *writer_tx != op.tx
+                            && !committed.contains(writer_tx)
+                            && !aborted.contains(writer_tx)
+                        {
+                            dirty_reads.push(RecoverabilityViolation::DirtyRead {
+                                reader: op.clone(),
+                                writer: writer_op.clone(),
+                            });
+                            pending_reads_from
+                                .entry(op.tx.clone())
+                                .or_default()
+                                .push((writer_tx.clone(), writer_op.clone()));
+                        }
+                    }
+                }
+                OpKind::Write(data) => {
+                    if let Some((writer_tx, writer_op)) = last_write.get(data) {
+                        if *writer_tx != op.tx
+                            && !committed.contains(writer_tx)
+                            && !aborted.contains(writer_tx)
+                        {
+                            dirty_writes.push(RecoverabilityViolation::DirtyWrite {
+                                writer: op.clone(),
+                                prior_writer: writer_op.clone(),
+                            });
+                        }
+                    }
+                    last_write.insert(data.clone(), (op.tx.clone(), op.clone()));
+                }
+                OpKind::Commit => {
+                    for (writer_tx, dirty_write) in
+                        pending_reads_from.remove(&op.tx).into_iter().flatten()
+                    {
+                        if !committed.contains(&writer_tx) {
+                            out_of_order_commits.push(RecoverabilityViolation::OutOfOrderCommit {
+                                reader_commit: op.clone(),
+                                dirty_write,
+                            });
+                        }
+                    }
+                    committed.insert(op.tx.clone());
+                }
+                OpKind::Abort => {
+                    aborted.insert(op.tx.clone());
+                }
+            }
+        }
+
+        let (level, violations) = if !out_of_order_commits.is_empty() {
+            (Recoverability::NonRecoverable, out_of_order_commits)
+        } else if !dirty_reads.is_empty() {
+            (Recoverability::Recoverable, dirty_reads)
+        } else if !dirty_writes.is_empty() {
+            (Recoverability::Cascadeless, dirty_writes)
+        } else {
+            (Recoverability::Strict, Vec::new())
+        };
+
+        RecoverabilityReport { level, violations }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum LockMode {
+    Shared,
+    Exclusive,
+}
+
+#[derive(Debug)]
+struct LockState {
+    mode: LockMode,
+    holders: BTreeSet<TxId>,
+}
+
+impl Schedule {
+    /// Locks release only at `Commit`/`Abort`, and a shared lock upgrades to
+    /// exclusive in place if its holder is the sole holder. Returns the
+    /// first op whose lock can't be granted, meaning no S2PL scheduler could
+    /// have produced this interleaving — a distinct question from conflict
+    /// serializability.
+    fn is_legal_under_s2pl(&self) -> Result<(), Op> {
+        let mut locks: BTreeMap<Data, LockState> = BTreeMap::new();
+        let mut held_by_tx: BTreeMap<TxId, BTreeSet<Data>> = BTreeMap::new();
+
+        for op in &self.ops {
+            match &op.kind {
+                OpKind::Read(data) => {
+                    Self::acquire(&mut locks, data, &op.tx, LockMode::Shared)
+                        .ok_or_else(|| op.clone())?;
+                    held_by_tx
+                        .entry(op.tx.clone())
+                        .or_default()
+                        .insert(data.clone());
+                }
+                OpKind::Write(data) => {
+                    Self::acquire(&mut locks, data, &op.tx, LockMode::Exclusive)
+                        .ok_or_else(|| op.clone())?;
+                    held_by_tx
+                        .entry(op.tx.clone())
+                        .or_default()
+                        .insert(data.clone());
+                }
+                OpKind::Commit | OpKind::Abort => {
+                    for data in held_by_tx.remove(&op.tx).into_iter().flatten() {
+                        if let Some(lock) = locks.get_mut(&data) {
+                            lock.holders.remove(&op.tx);
+                            if lock.holders.is_empty() {
+                                locks.remove(&data);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn acquire(
+        locks: &mut BTreeMap<Data, LockState>,
+        data: &Data,
+        tx: &TxId,
+        mode: LockMode,
+    ) -> Option<()> {
+        match locks.get_mut(data) {
+            None => {
+                locks.insert(
+                    data.clone(),
+                    LockState {
+                        mode,
+                        holders: BTreeSet::from([tx.clone()]),
+                    },
+                );
+            }
+            Some(lock) => {
+                let sole_holder = lock.holders.len() == 1 && lock.holders.contains(tx);
+
+                match (lock.mode, mode) {
+                    (LockMode::Shared, LockMode::Shared) => {
+                        lock.holders.insert(tx.clone());
+                    }
+                    (LockMode::Shared, LockMode::Exclusive) if sole_holder => {
+                        lock.mode = LockMode::Exclusive;
+                    }
+                    (LockMode::Exclusive, _) if sole_holder => {}
+                    _ => return None,
+                }
+            }
+        }
+
+        Some(())
+    }
+}
+
+/// Default cap passed to `Schedule::is_view_serializable`: above this many
+/// committed transactions it gives up rather than enumerate all `n!`
+/// orderings — checking view serializability in general is NP-complete.
+const MAX_VIEW_SERIALIZABLE_TRANSACTIONS: usize = 8;
+
+#[derive(Debug)]
+enum ViewSerializability {
+    Serializable(Vec<TxId>),
+    NotSerializable,
+    TooManyTransactions { count: usize, cap: usize },
+}
+
+impl Display for ViewSerializability {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ViewSerializability::Serializable(order) => {
+                let order = order
+                    .iter()
+                    .map(|tx| tx.0.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "true (witness: [{}])", order)
+            }
+            ViewSerializability::NotSerializable => write!(f, "false"),
+            ViewSerializability::TooManyTransactions { count, cap } => write!(
+                f,
+                "unknown ({} committed transactions exceeds the cap of {})",
+                count, cap
+            ),
+        }
+    }
+}
+
+/// Two schedules are view equivalent iff both relations match.
+#[derive(Debug, PartialEq, Eq)]
+struct ViewEquivalence {
+    reads_from: BTreeMap<(TxId, Data), Option<TxId>>,
+    final_writer: BTreeMap<Data, TxId>,
+}
+
+fn reads_from_and_final_writer(ops: &[Op]) -> ViewEquivalence {
+    let reads_from = Schedule::new(ops)
+        .reads_from()
+        .into_iter()
+        .filter_map(|(op, writer)| match op.kind {
+            OpKind::Read(data) => Some(((op.tx, data), writer)),
+            OpKind::Write(_) | OpKind::Commit | OpKind::Abort => None,
+        })
+        .collect();
+
+    let mut final_writer: BTreeMap<Data, TxId> = BTreeMap::new();
+    for op in ops {
+        if let OpKind::Write(data) = &op.kind {
+            final_writer.insert(data.clone(), op.tx.clone());
+        }
+    }
+
+    ViewEquivalence {
+        reads_from,
+        final_writer,
+    }
+}
+
+impl Schedule {
+    /// Searches permutations of the committed transactions, giving up if
+    /// there are more than `max_transactions` of them — checking view
+    /// serializability in general is NP-complete.
+    fn is_view_serializable(&self, max_transactions: usize) -> ViewSerializability {
+        let committed: BTreeSet<TxId> = self
+            .ops
+            .iter()
+            .filter(|op| matches!(op.kind, OpKind::Commit))
+            .map(|op| op.tx.clone())
+            .collect();
+
+        let committed_ops: Vec<Op> = self
+            .ops
+            .iter()
+            .filter(|op| {
+                committed.contains(&op.tx) && !matches!(op.kind, OpKind::Commit | OpKind::Abort)
+            })
+            .cloned()
+            .collect();
+
+        let tx_ids: Vec<TxId> = committed.into_iter().collect();
+
+        if tx_ids.len() > max_transactions {
+            return ViewSerializability::TooManyTransactions {
+                count: tx_ids.len(),
+                cap: max_transactions,
+            };
+        }
+
+        let target = reads_from_and_final_writer(&committed_ops);
+
+        let mut ops_by_tx: BTreeMap<TxId, Vec<Op>> =
+            tx_ids.iter().cloned().map(|tx| (tx, Vec::new())).collect();
+        for op in &committed_ops {
+            ops_by_tx.entry(op.tx.clone()).or_default().push(op.clone());
+        }
+
+        let found = tx_ids
+            .iter()
+            .cloned()
+            .permutations(tx_ids.len())
+            .find(|order| {
+                let candidate_ops: Vec<Op> = order
+                    .iter()
+                    .flat_map(|tx| ops_by_tx[tx].clone())
+                    .collect();
+
+                reads_from_and_final_writer(&candidate_ops) == target
+            });
+
+        match found {
+            Some(order) => ViewSerializability::Serializable(order),
+            None => ViewSerializability::NotSerializable,
+        }
+    }
 }
 
 impl Display for Schedule {
@@ -192,6 +794,30 @@ impl Display for Schedule {
     }
 }
 
+impl FromStr for Schedule {
+    type Err = ParseOpError;
+
+    /// Parses the textbook notation `Schedule`'s `Display` impl emits, e.g.
+    /// `[R_1(A), W_2(A), C_1]`, with the brackets optional and tokens
+    /// separated by commas and/or whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let inner = match (trimmed.strip_prefix('['), trimmed.ends_with(']')) {
+            (Some(rest), true) => &rest[..rest.len() - 1],
+            (None, false) => trimmed,
+            _ => return Err(ParseOpError(trimmed.to_string())),
+        };
+
+        let ops = inner
+            .replace(',', " ")
+            .split_whitespace()
+            .map(Op::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Schedule::new(&ops))
+    }
+}
+
 fn schedule_report(schedule: &Schedule) {
     println!("Schedule:");
     println!("\t{}", schedule);
@@ -205,9 +831,153 @@ fn schedule_report(schedule: &Schedule) {
 
     println!("Conflicting Pairs:");
     println!("\t{}", schedule.conflicting_pairs().display());
+    println!();
+
+    println!("Reads-From:");
+    for (read, writer) in schedule.reads_from() {
+        match writer {
+            Some(tx) => println!("\t{} reads from {}", read, tx.0),
+            None => println!("\t{} reads the initial value", read),
+        }
+    }
+    println!();
+
+    println!("Write Dependencies:");
+    for dependency in schedule.write_dependencies() {
+        println!("\t{}", dependency);
+    }
+    println!();
+
+    println!("Conflict Serializable: {}", schedule.is_conflict_serializable());
+    if let Some(order) = schedule.serial_equivalent() {
+        let order = order
+            .iter()
+            .map(|tx| tx.0.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("Serial Equivalent: [{}]", order);
+    }
+    println!();
+
+    let recoverability = schedule.recoverability();
+    println!("Recoverability: {}", recoverability.level);
+    for violation in &recoverability.violations {
+        println!("\t{}", violation);
+    }
+    println!();
+
+    match schedule.is_legal_under_s2pl() {
+        Ok(()) => println!("Legal Under Strict 2PL: true"),
+        Err(op) => println!("Legal Under Strict 2PL: false (blocked at {})", op),
+    }
+    println!();
+
+    println!(
+        "View Serializable: {}",
+        schedule.is_view_serializable(MAX_VIEW_SERIALIZABLE_TRANSACTIONS)
+    );
 }
 
 fn main() {
     let schedule = sched!((r, a, 1), (w, a, 2), (a, 2), (c, 1));
     schedule_report(&schedule);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_parse_round_trips_through_display() {
+        let original = "[R_1(A), W_2(A), C_1]";
+        let schedule: Schedule = original.parse().unwrap();
+        assert_eq!(schedule.to_string(), original);
+    }
+
+    #[test]
+    fn schedule_parse_rejects_a_mismatched_bracket() {
+        assert!("R_1(A), C_1]".parse::<Schedule>().is_err());
+        assert!("[R_1(A), C_1".parse::<Schedule>().is_err());
+    }
+
+    #[test]
+    fn precedence_graph_detects_a_cycle() {
+        let cyclic = sched!((r, a, 1), (w, a, 2), (r, b, 2), (w, b, 1));
+        assert!(cyclic.precedence_graph().has_cycle());
+
+        let acyclic = sched!((r, a, 1), (w, a, 1), (w, a, 2));
+        assert!(!acyclic.precedence_graph().has_cycle());
+    }
+
+    #[test]
+    fn recoverability_classifies_each_level() {
+        let strict = sched!((w, a, 1), (c, 1), (w, a, 2), (c, 2));
+        assert_eq!(strict.recoverability().level, Recoverability::Strict);
+
+        let cascadeless = sched!((w, a, 1), (w, a, 2), (c, 1), (c, 2));
+        assert_eq!(cascadeless.recoverability().level, Recoverability::Cascadeless);
+
+        let recoverable = sched!((w, a, 1), (r, a, 2), (c, 1), (c, 2));
+        assert_eq!(recoverable.recoverability().level, Recoverability::Recoverable);
+
+        let nonrecoverable = sched!((w, a, 1), (r, a, 2), (c, 2), (c, 1));
+        assert_eq!(
+            nonrecoverable.recoverability().level,
+            Recoverability::NonRecoverable
+        );
+    }
+
+    #[test]
+    fn s2pl_blocks_a_conflicting_lock_but_allows_a_serial_one() {
+        let legal = sched!((w, a, 1), (c, 1), (r, a, 2), (c, 2));
+        assert!(legal.is_legal_under_s2pl().is_ok());
+
+        let blocked = sched!((w, a, 1), (w, a, 2));
+        assert!(blocked.is_legal_under_s2pl().is_err());
+    }
+
+    #[test]
+    fn reads_from_ignores_an_aborted_writer() {
+        let schedule = sched!((w, a, 1), (a, 1), (r, a, 2), (c, 2));
+        let (_, writer) = schedule
+            .reads_from()
+            .into_iter()
+            .find(|(op, _)| op.tx == TxId("2".to_string()))
+            .unwrap();
+        assert_eq!(writer, None);
+    }
+
+    #[test]
+    fn write_dependencies_classify_each_hazard() {
+        let schedule = sched!((w, a, 1), (r, a, 2), (w, a, 3));
+        let hazards: Vec<Hazard> = schedule
+            .write_dependencies()
+            .into_iter()
+            .map(|wd| wd.hazard)
+            .collect();
+        assert_eq!(hazards, vec![Hazard::Raw, Hazard::Waw, Hazard::War]);
+    }
+
+    #[test]
+    fn view_serializable_but_not_conflict_serializable() {
+        // T1 reads Q's initial value, then T3 and T1 overwrite it before T2's
+        // blind write settles the final value — no serial order agrees with
+        // the conflict ordering, but [T1, T3, T2] reproduces the same
+        // reads-from and final-writer relations.
+        let schedule = sched!(
+            (r, q, 1),
+            (w, q, 3),
+            (w, q, 1),
+            (w, q, 2),
+            (c, 1),
+            (c, 2),
+            (c, 3)
+        );
+
+        assert!(!schedule.is_conflict_serializable());
+        assert!(matches!(
+            schedule.is_view_serializable(8),
+            ViewSerializability::Serializable(_)
+        ));
+    }
+}